@@ -1,27 +1,78 @@
 use crate::reader::DataRecord;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::error::Error;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+
+/// The attribute-scoring criterion used to pick each split.
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum SplitQuality {
+    /// C4.5 information gain normalized by split info; penalizes
+    /// high-cardinality attributes like `county`.
+    GainRatio,
+    /// Raw entropy reduction, unnormalized.
+    InformationGain,
+    /// CART-style Gini impurity reduction.
+    GiniImpurity,
+}
 
 /// Pruning and stopping criteria.
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Serialize, Deserialize)]
 pub struct TreeParams {
     pub max_depth: usize,
     pub min_samples_leaf: usize,
+    /// Minimum required improvement under the active `split_quality`
+    /// criterion (gain ratio, information gain, or Gini decrease) for a
+    /// split to be worth making.
     pub min_gain_ratio: f64,
+    pub split_quality: SplitQuality,
 }
 
-#[derive(Debug)]
+/// Parameters for a bagged ensemble of `DecisionTree`s (see `RandomForestPredictor`).
+#[derive(Clone, Copy)]
+pub struct ForestParams {
+    pub tree: TreeParams,
+    pub n_trees: usize,
+    /// Fixed number of attributes considered at each split. `None` instead
+    /// recomputes `ceil(sqrt(k_remaining))` at every node from the
+    /// attributes still available there, as in linfa-trees.
+    pub feature_subset_size: Option<usize>,
+    pub seed: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
 pub enum TreeNode {
     Branch {
         attribute: String,
         children: HashMap<String, TreeNode>,
-        majority: String,
+        /// Class-count histogram over records reaching this node, used as
+        /// the fallback distribution when a record's value doesn't match
+        /// any known child.
+        counts: HashMap<String, usize>,
+    },
+    /// Binary split on a numeric attribute: `<= threshold` goes `left`,
+    /// `> threshold` goes `right`, and records where the attribute fails to
+    /// parse go to `missing` instead of being dropped from training.
+    NumericBranch {
+        attribute: String,
+        threshold: f64,
+        left: Box<TreeNode>,
+        right: Box<TreeNode>,
+        missing: Box<TreeNode>,
+        counts: HashMap<String, usize>,
     },
     Leaf {
-        value: String,
+        /// Class-count histogram of the training records that landed here.
+        counts: HashMap<String, usize>,
     },
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TargetField {
     Clinician,
     Gpt4_0,
@@ -30,88 +81,255 @@ pub enum TargetField {
     DdxSnomed,
 }
 
+/// A target's posterior distribution, ranked descending by probability.
+#[derive(Debug, Default, Clone)]
+pub struct TargetPrediction {
+    pub ranked: Vec<(String, f64)>,
+}
+
+impl TargetPrediction {
+    fn from_counts(counts: &HashMap<String, usize>) -> Self {
+        let total = (counts.values().sum::<usize>().max(1)) as f64;
+        let mut ranked: Vec<(String, f64)> = counts
+            .iter()
+            .map(|(label, &c)| (label.clone(), c as f64 / total))
+            .collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+        Self { ranked }
+    }
+
+    /// The top-ranked (most probable) label.
+    pub fn best(&self) -> Option<&str> {
+        self.ranked.first().map(|(label, _)| label.as_str())
+    }
+
+    /// Probability mass of the top-ranked label.
+    pub fn confidence(&self) -> f64 {
+        self.ranked.first().map(|(_, p)| *p).unwrap_or(0.0)
+    }
+
+    /// Every label below the top one, still sorted descending.
+    pub fn runner_ups(&self) -> &[(String, f64)] {
+        self.ranked.get(1..).unwrap_or(&[])
+    }
+
+    /// `best()` unless its confidence falls below `cutoff`, in which case
+    /// `None` — lets callers abstain instead of always taking the argmax.
+    pub fn best_above(&self, cutoff: f64) -> Option<&str> {
+        if self.confidence() >= cutoff { self.best() } else { None }
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct Prediction {
-    pub clinician: Option<String>,
-    pub gpt4_0: Option<String>,
-    pub llama: Option<String>,
-    pub gemini: Option<String>,
-    pub ddx_snomed: Option<String>,
+    pub clinician: TargetPrediction,
+    pub gpt4_0: TargetPrediction,
+    pub llama: TargetPrediction,
+    pub gemini: TargetPrediction,
+    pub ddx_snomed: TargetPrediction,
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct DecisionTree {
     root: TreeNode,
     _params: TreeParams,
+    /// Normalized (sum to 1.0) impurity-decrease contribution per attribute.
+    importances: HashMap<String, f64>,
 }
 
 impl DecisionTree {
-    /// Build a pruned tree.
+    /// Build a pruned tree over all attributes.
     pub fn build(
         records: &[DataRecord],
         target: TargetField,
         params: TreeParams,
     ) -> Self {
         let attrs = Self::all_attributes();
-        let root = Self::recurse(records, target, attrs, 0, &params);
-        DecisionTree { root, _params: params }
+        let mut raw_importances = HashMap::new();
+        let root = Self::recurse(records, target, attrs, 0, &params, None, Some(&mut raw_importances));
+        DecisionTree { root, _params: params, importances: Self::normalize_importances(raw_importances) }
+    }
+
+    /// Build a tree that, at every split, only considers a random subset of
+    /// the attributes still available at that node — `feature_subset_size`
+    /// attributes if given, else `ceil(sqrt(k_remaining))` recomputed at each
+    /// node as attributes get consumed going down the tree. Used by
+    /// `RandomForestPredictor` to decorrelate the trees in its ensemble.
+    fn build_for_forest(
+        records: &[DataRecord],
+        target: TargetField,
+        params: TreeParams,
+        feature_subset_size: Option<usize>,
+        rng: &mut StdRng,
+    ) -> Self {
+        let attrs = Self::all_attributes();
+        let mut raw_importances = HashMap::new();
+        let root = Self::recurse(
+            records,
+            target,
+            attrs,
+            0,
+            &params,
+            Some((feature_subset_size, rng)),
+            Some(&mut raw_importances),
+        );
+        DecisionTree { root, _params: params, importances: Self::normalize_importances(raw_importances) }
+    }
+
+    fn normalize_importances(raw: HashMap<String, f64>) -> HashMap<String, f64> {
+        let total: f64 = raw.values().sum();
+        if total <= 0.0 {
+            return raw;
+        }
+        raw.into_iter().map(|(attr, v)| (attr, v / total)).collect()
+    }
+
+    /// Per-attribute share (summing to 1.0) of the impurity decrease this
+    /// tree's splits are responsible for.
+    pub fn feature_importances(&self) -> &HashMap<String, f64> {
+        &self.importances
     }
 
     fn all_attributes() -> &'static [&'static str] {
         &["county", "health_level", "years_experience", "clinical_panel"]
     }
 
+    /// Attributes treated as continuous (threshold-split) rather than
+    /// categorical.
+    fn is_numeric_attribute(attr: &str) -> bool {
+        attr == "years_experience"
+    }
+
     fn recurse(
         records: &[DataRecord],
         target: TargetField,
         attributes: &[&str],
         depth: usize,
         params: &TreeParams,
+        mut feature_sample: Option<(Option<usize>, &mut StdRng)>,
+        mut importances: Option<&mut HashMap<String, f64>>,
     ) -> TreeNode {
-        let majority = Self::majority(records, target);
+        let counts = Self::leaf_counts(records, target);
         if records.is_empty() {
-            return TreeNode::Leaf { value: "unknown".into() };
+            return TreeNode::Leaf { counts };
         }
         if depth >= params.max_depth || records.len() < params.min_samples_leaf {
-            return TreeNode::Leaf { value: majority };
+            return TreeNode::Leaf { counts };
         }
-        if let Some(pure_val) = Self::pure(records, target) {
-            return TreeNode::Leaf { value: pure_val };
+        if Self::pure(records, target).is_some() {
+            return TreeNode::Leaf { counts };
         }
         if attributes.is_empty() {
-            return TreeNode::Leaf { value: majority };
+            return TreeNode::Leaf { counts };
         }
 
+        let candidates: Vec<&str> = match feature_sample.as_mut() {
+            Some((override_size, rng)) => {
+                let k = override_size
+                    .unwrap_or_else(|| (attributes.len() as f64).sqrt().ceil() as usize)
+                    .clamp(1, attributes.len());
+                let mut shuffled = attributes.to_vec();
+                shuffled.shuffle(*rng);
+                shuffled.truncate(k);
+                shuffled
+            }
+            None => attributes.to_vec(),
+        };
+
         let base_ent = Self::entropy(records, target);
         let mut best_attr: &str = "";
         let mut best_ratio = 0.0;
-        for &attr in attributes {
-            let ratio = Self::gain_ratio(records, attr, target, base_ent);
-            if ratio > best_ratio {
-                best_ratio = ratio;
-                best_attr = attr;
+        let mut best_threshold: Option<f64> = None;
+        for &attr in &candidates {
+            if Self::is_numeric_attribute(attr) {
+                if let Some((ratio, threshold)) =
+                    Self::best_numeric_split(records, attr, target, base_ent, params.split_quality)
+                {
+                    if ratio > best_ratio {
+                        best_ratio = ratio;
+                        best_attr = attr;
+                        best_threshold = Some(threshold);
+                    }
+                }
+            } else {
+                let ratio = Self::categorical_score(records, attr, target, base_ent, params.split_quality);
+                if ratio > best_ratio {
+                    best_ratio = ratio;
+                    best_attr = attr;
+                    best_threshold = None;
+                }
             }
         }
-        
+
         if best_ratio < params.min_gain_ratio {
-            return TreeNode::Leaf { value: majority };
+            return TreeNode::Leaf { counts };
+        }
+
+        let weighted_child_entropy = match best_threshold {
+            Some(threshold) => Self::weighted_entropy_numeric(records, best_attr, threshold, target),
+            None => Self::weighted_entropy_categorical(records, best_attr, target),
+        };
+        if let Some(acc) = importances.as_mut() {
+            let weight = records.len() as f64;
+            *acc.entry(best_attr.to_string()).or_insert(0.0) += weight * (base_ent - weighted_child_entropy);
+        }
+
+        let rem: Vec<&str> = attributes
+            .iter()
+            .copied()
+            .filter(|&a| a != best_attr)
+            .collect();
+
+        if let Some(threshold) = best_threshold {
+            let (left, right, missing) = Self::numeric_split(records, best_attr, threshold);
+
+            let left_sample = feature_sample.as_mut().map(|(k, rng)| (*k, &mut **rng));
+            let left_importances = importances.as_deref_mut();
+            let left_node = if left.len() < params.min_samples_leaf {
+                TreeNode::Leaf { counts: Self::leaf_counts(&left, target) }
+            } else {
+                Self::recurse(&left, target, &rem, depth + 1, params, left_sample, left_importances)
+            };
+
+            let right_sample = feature_sample.as_mut().map(|(k, rng)| (*k, &mut **rng));
+            let right_importances = importances.as_deref_mut();
+            let right_node = if right.len() < params.min_samples_leaf {
+                TreeNode::Leaf { counts: Self::leaf_counts(&right, target) }
+            } else {
+                Self::recurse(&right, target, &rem, depth + 1, params, right_sample, right_importances)
+            };
+
+            let missing_sample = feature_sample.as_mut().map(|(k, rng)| (*k, &mut **rng));
+            let missing_importances = importances.as_deref_mut();
+            let missing_node = if missing.len() < params.min_samples_leaf {
+                TreeNode::Leaf { counts: Self::leaf_counts(&missing, target) }
+            } else {
+                Self::recurse(&missing, target, &rem, depth + 1, params, missing_sample, missing_importances)
+            };
+
+            return TreeNode::NumericBranch {
+                attribute: best_attr.into(),
+                threshold,
+                left: Box::new(left_node),
+                right: Box::new(right_node),
+                missing: Box::new(missing_node),
+                counts,
+            };
         }
 
         let mut children = HashMap::new();
         for (val, subset) in Self::partition(records, best_attr) {
             let node = if subset.len() < params.min_samples_leaf {
-                TreeNode::Leaf { value: majority.clone() }
+                TreeNode::Leaf { counts: Self::leaf_counts(&subset, target) }
             } else {
-                let rem: Vec<&str> = attributes
-                    .iter()
-                    .copied()
-                    .filter(|&a| a != best_attr)
-                    .collect();
-                Self::recurse(&subset, target, &rem, depth + 1, params)
+                let child_sample = feature_sample.as_mut().map(|(k, rng)| (*k, &mut **rng));
+                let child_importances = importances.as_deref_mut();
+                Self::recurse(&subset, target, &rem, depth + 1, params, child_sample, child_importances)
             };
             children.insert(val, node);
         }
 
-        TreeNode::Branch { attribute: best_attr.into(), children, majority }
+        TreeNode::Branch { attribute: best_attr.into(), children, counts }
     }
 
     fn pure(records: &[DataRecord], target: TargetField) -> Option<String> {
@@ -151,6 +369,152 @@ impl DecisionTree {
         if split_info == 0.0 { 0.0 } else { gain / split_info }
     }
 
+    /// Sample-weighted average entropy across a categorical attribute's
+    /// partitions, used for feature-importance bookkeeping.
+    fn weighted_entropy_categorical(records: &[DataRecord], attr: &str, target: TargetField) -> f64 {
+        let total = records.len() as f64;
+        Self::partition(records, attr)
+            .values()
+            .map(|subset| (subset.len() as f64 / total) * Self::entropy(subset, target))
+            .sum()
+    }
+
+    /// Gini impurity `1 - Σ p_c²` over target-class proportions.
+    fn gini(records: &[DataRecord], target: TargetField) -> f64 {
+        let total = records.len() as f64;
+        let mut counts: HashMap<&String, usize> = HashMap::new();
+        for val in records.iter().filter_map(|r| Self::get_target(r, target)) {
+            *counts.entry(val).or_insert(0) += 1;
+        }
+        1.0 - counts.values().fold(0.0, |acc, &c| {
+            let p = (c as f64) / total;
+            acc + p * p
+        })
+    }
+
+    fn weighted_gini_categorical(records: &[DataRecord], attr: &str, target: TargetField) -> f64 {
+        let total = records.len() as f64;
+        Self::partition(records, attr)
+            .values()
+            .map(|subset| (subset.len() as f64 / total) * Self::gini(subset, target))
+            .sum()
+    }
+
+    /// Score a categorical attribute's split under the active `SplitQuality`.
+    fn categorical_score(
+        records: &[DataRecord],
+        attr: &str,
+        target: TargetField,
+        base_ent: f64,
+        quality: SplitQuality,
+    ) -> f64 {
+        match quality {
+            SplitQuality::GainRatio => Self::gain_ratio(records, attr, target, base_ent),
+            SplitQuality::InformationGain => base_ent - Self::weighted_entropy_categorical(records, attr, target),
+            SplitQuality::GiniImpurity => {
+                Self::gini(records, target) - Self::weighted_gini_categorical(records, attr, target)
+            }
+        }
+    }
+
+    /// Sample-weighted average entropy across a numeric attribute's
+    /// `<= threshold` / `> threshold` / `missing` split.
+    fn weighted_entropy_numeric(records: &[DataRecord], attr: &str, threshold: f64, target: TargetField) -> f64 {
+        let total = records.len() as f64;
+        let (left, right, missing) = Self::numeric_split(records, attr, threshold);
+        (left.len() as f64 / total) * Self::entropy(&left, target)
+            + (right.len() as f64 / total) * Self::entropy(&right, target)
+            + (missing.len() as f64 / total) * Self::entropy(&missing, target)
+    }
+
+    /// Parses `attr` as a finite `f64`, treating `"NaN"`/`"inf"` — valid
+    /// `f64::from_str` input but not a total order — as unparseable so they
+    /// route through the `missing` branch instead of panicking a sort.
+    fn parse_numeric(r: &DataRecord, attr: &str) -> Option<f64> {
+        Self::get_attr(r, attr)?.parse::<f64>().ok().filter(|v| v.is_finite())
+    }
+
+    /// Midpoints between consecutive distinct parsed values of `attr`,
+    /// i.e. the candidate thresholds for a binary numeric split.
+    fn numeric_thresholds(records: &[DataRecord], attr: &str) -> Vec<f64> {
+        let mut values: Vec<f64> = records
+            .iter()
+            .filter_map(|r| Self::parse_numeric(r, attr))
+            .collect();
+        values.sort_by(f64::total_cmp);
+        values.dedup();
+        values.windows(2).map(|w| (w[0] + w[1]) / 2.0).collect()
+    }
+
+    /// Partitions `records` into `<= threshold`, `> threshold`, and a third
+    /// `missing` group for records whose `attr` fails to parse, so that
+    /// `left.len() + right.len() + missing.len() == records.len()`.
+    fn numeric_split(
+        records: &[DataRecord],
+        attr: &str,
+        threshold: f64,
+    ) -> (Vec<DataRecord>, Vec<DataRecord>, Vec<DataRecord>) {
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+        let mut missing = Vec::new();
+        for r in records {
+            match Self::parse_numeric(r, attr) {
+                Some(v) if v <= threshold => left.push(r.clone()),
+                Some(_) => right.push(r.clone()),
+                None => missing.push(r.clone()),
+            }
+        }
+        (left, right, missing)
+    }
+
+    /// Best `(score, threshold)` over all candidate thresholds for a numeric
+    /// attribute, scored under the active `SplitQuality`.
+    fn best_numeric_split(
+        records: &[DataRecord],
+        attr: &str,
+        target: TargetField,
+        base_ent: f64,
+        quality: SplitQuality,
+    ) -> Option<(f64, f64)> {
+        let base_gini = Self::gini(records, target);
+        let mut best: Option<(f64, f64)> = None;
+        for threshold in Self::numeric_thresholds(records, attr) {
+            let (left, right, _missing) = Self::numeric_split(records, attr, threshold);
+            if left.is_empty() || right.is_empty() {
+                continue;
+            }
+            // Score over the parseable rows only; `missing` never factors into
+            // threshold selection since there's no value to compare.
+            let total = (left.len() + right.len()) as f64;
+            let p_left = left.len() as f64 / total;
+            let p_right = right.len() as f64 / total;
+            let ratio = match quality {
+                SplitQuality::GainRatio => {
+                    let info_attr = p_left * Self::entropy(&left, target) + p_right * Self::entropy(&right, target);
+                    let split_info = -(p_left * p_left.log2() + p_right * p_right.log2());
+                    let gain = base_ent - info_attr;
+                    if split_info == 0.0 { 0.0 } else { gain / split_info }
+                }
+                SplitQuality::InformationGain => {
+                    let info_attr = p_left * Self::entropy(&left, target) + p_right * Self::entropy(&right, target);
+                    base_ent - info_attr
+                }
+                SplitQuality::GiniImpurity => {
+                    let weighted_gini = p_left * Self::gini(&left, target) + p_right * Self::gini(&right, target);
+                    base_gini - weighted_gini
+                }
+            };
+            let is_better = match best {
+                Some((best_ratio, _)) => ratio > best_ratio,
+                None => true,
+            };
+            if is_better {
+                best = Some((ratio, threshold));
+            }
+        }
+        best
+    }
+
     fn partition(
         records: &[DataRecord],
         attr: &str,
@@ -164,16 +528,17 @@ impl DecisionTree {
         map
     }
 
-    fn majority(records: &[DataRecord], target: TargetField) -> String {
-        let mut counts: HashMap<&String, usize> = HashMap::new();
+    /// Class-count histogram for `records`, falling back to a single
+    /// "unknown" count when no record has a value for `target`.
+    fn leaf_counts(records: &[DataRecord], target: TargetField) -> HashMap<String, usize> {
+        let mut counts: HashMap<String, usize> = HashMap::new();
         for val in records.iter().filter_map(|r| Self::get_target(r, target)) {
-            *counts.entry(val).or_insert(0) += 1;
+            *counts.entry(val.clone()).or_insert(0) += 1;
+        }
+        if counts.is_empty() {
+            counts.insert("unknown".into(), 1);
         }
         counts
-            .into_iter()
-            .max_by_key(|&(_, c)| c)
-            .map(|(v, _)| v.clone())
-            .unwrap_or_else(|| "unknown".into())
     }
 
     fn get_target<'a>(r: &'a DataRecord, t: TargetField) -> Option<&'a String> {
@@ -196,29 +561,37 @@ impl DecisionTree {
         }
     }
 
-    pub fn predict(&self, rec: &DataRecord) -> Option<String> {
-        DecisionTree::traverse(&self.root, rec)
+    /// Ranked posterior distribution for `rec`, descending by probability.
+    pub fn predict(&self, rec: &DataRecord) -> TargetPrediction {
+        TargetPrediction::from_counts(DecisionTree::traverse(&self.root, rec))
     }
 
-    fn traverse(node: &TreeNode, rec: &DataRecord) -> Option<String> {
+    fn traverse<'a>(node: &'a TreeNode, rec: &DataRecord) -> &'a HashMap<String, usize> {
         match node {
-            TreeNode::Leaf { value } => Some(value.clone()),
-            TreeNode::Branch { attribute, children, majority } => {
+            TreeNode::Leaf { counts } => counts,
+            TreeNode::Branch { attribute, children, counts } => {
                 let raw = DecisionTree::get_attr(rec, attribute)
                     .map(|s| s.to_lowercase())
                     .unwrap_or_else(|| "missing".into());
-                let key = children.keys()
-                    .find(|k| k.eq_ignore_ascii_case(&raw))
-                    .cloned()
-                    .unwrap_or_else(|| majority.clone());
-                children.get(&key)
-                    .and_then(|n| DecisionTree::traverse(n,	rec))
-                    .or_else(|| Some(majority.clone()))
+                match children.keys().find(|k| k.eq_ignore_ascii_case(&raw)) {
+                    Some(key) => DecisionTree::traverse(&children[key], rec),
+                    None => counts,
+                }
+            }
+            TreeNode::NumericBranch { attribute, threshold, left, right, missing, counts: _ } => {
+                match DecisionTree::parse_numeric(rec, attribute) {
+                    Some(v) => {
+                        let branch = if v <= *threshold { left } else { right };
+                        DecisionTree::traverse(branch, rec)
+                    }
+                    None => DecisionTree::traverse(missing, rec),
+                }
             }
         }
     }
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct MultiTargetPredictor {
     clinician_tree: DecisionTree,
     gpt4_tree: DecisionTree,
@@ -247,4 +620,95 @@ impl MultiTargetPredictor {
             ddx_snomed:self.ddx_snomed_tree.predict(rec),
         }
     }
+
+    /// Write the trained forest-of-five to `path` as JSON so it can be
+    /// reloaded without retraining.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Box<dyn Error>> {
+        let writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer(writer, self)?;
+        Ok(())
+    }
+
+    /// Load a predictor previously written by `save`.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn Error>> {
+        let reader = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(reader)?)
+    }
+
+    /// Per-target, per-attribute share of impurity decrease, ranking which
+    /// of `county`, `health_level`, `years_experience`, `clinical_panel`
+    /// drives each target's predictions.
+    pub fn feature_importances(&self) -> HashMap<TargetField, HashMap<String, f64>> {
+        HashMap::from([
+            (TargetField::Clinician, self.clinician_tree.feature_importances().clone()),
+            (TargetField::Gpt4_0, self.gpt4_tree.feature_importances().clone()),
+            (TargetField::Llama, self.llama_tree.feature_importances().clone()),
+            (TargetField::Gemini, self.gemini_tree.feature_importances().clone()),
+            (TargetField::DdxSnomed, self.ddx_snomed_tree.feature_importances().clone()),
+        ])
+    }
+}
+
+/// Bagged ensemble of `DecisionTree`s per target, trading the single C4.5
+/// tree's variance for the stability of majority voting across `n_trees`
+/// bootstrap-sampled, feature-subsampled trees (cf. linfa-trees' forests).
+pub struct RandomForestPredictor {
+    clinician_forest: Vec<DecisionTree>,
+    gpt4_forest: Vec<DecisionTree>,
+    llama_forest: Vec<DecisionTree>,
+    gemini_forest: Vec<DecisionTree>,
+    ddx_snomed_forest: Vec<DecisionTree>,
+}
+
+impl RandomForestPredictor {
+    pub fn build(records: &[DataRecord], params: ForestParams) -> Self {
+        let mut rng = StdRng::seed_from_u64(params.seed);
+        Self {
+            clinician_forest: Self::build_forest(records, TargetField::Clinician, params, &mut rng),
+            gpt4_forest:      Self::build_forest(records, TargetField::Gpt4_0, params, &mut rng),
+            llama_forest:     Self::build_forest(records, TargetField::Llama, params, &mut rng),
+            gemini_forest:    Self::build_forest(records, TargetField::Gemini, params, &mut rng),
+            ddx_snomed_forest:Self::build_forest(records, TargetField::DdxSnomed, params, &mut rng),
+        }
+    }
+
+    fn build_forest(
+        records: &[DataRecord],
+        target: TargetField,
+        params: ForestParams,
+        rng: &mut StdRng,
+    ) -> Vec<DecisionTree> {
+        (0..params.n_trees)
+            .map(|_| {
+                let sample = Self::bootstrap_sample(records, rng);
+                DecisionTree::build_for_forest(&sample, target, params.tree, params.feature_subset_size, rng)
+            })
+            .collect()
+    }
+
+    fn bootstrap_sample(records: &[DataRecord], rng: &mut StdRng) -> Vec<DataRecord> {
+        let n = records.len();
+        (0..n).map(|_| records[rng.gen_range(0..n)].clone()).collect()
+    }
+
+    pub fn predict(&self, rec: &DataRecord) -> Prediction {
+        Prediction {
+            clinician: Self::vote(&self.clinician_forest, rec),
+            gpt4_0:    Self::vote(&self.gpt4_forest, rec),
+            llama:     Self::vote(&self.llama_forest, rec),
+            gemini:    Self::vote(&self.gemini_forest, rec),
+            ddx_snomed:Self::vote(&self.ddx_snomed_forest, rec),
+        }
+    }
+
+    /// Vote distribution across the forest's per-tree top predictions.
+    fn vote(forest: &[DecisionTree], rec: &DataRecord) -> TargetPrediction {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for tree in forest {
+            if let Some(label) = tree.predict(rec).best() {
+                *counts.entry(label.to_string()).or_insert(0) += 1;
+            }
+        }
+        TargetPrediction::from_counts(&counts)
+    }
 }
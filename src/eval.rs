@@ -0,0 +1,181 @@
+// src/eval.rs
+
+use crate::decision_tree::{MultiTargetPredictor, Prediction, TreeParams};
+use crate::reader::DataRecord;
+use std::collections::{BTreeSet, HashMap};
+
+/// Accuracy, macro-F1, and the raw confusion matrix for a single target.
+pub struct TargetMetrics {
+    pub accuracy: f64,
+    pub macro_f1: f64,
+    pub confusion: HashMap<(String, String), usize>,
+}
+
+impl TargetMetrics {
+    fn from_confusion(confusion: HashMap<(String, String), usize>) -> Self {
+        let total: usize = confusion.values().sum();
+        let correct: usize = confusion
+            .iter()
+            .filter(|((gold, predicted), _)| gold == predicted)
+            .map(|(_, &c)| c)
+            .sum();
+        let accuracy = if total == 0 { 0.0 } else { correct as f64 / total as f64 };
+
+        // Average over gold classes only, not predicted-only labels — a label
+        // the model predicts but that never occurs as gold would score F1=0
+        // and bias macro-F1 downward if it were included here.
+        let labels: BTreeSet<&String> = confusion.keys().map(|(g, _)| g).collect();
+        let macro_f1 = if labels.is_empty() {
+            0.0
+        } else {
+            let sum: f64 = labels.iter().map(|label| Self::f1_for(&confusion, label)).sum();
+            sum / labels.len() as f64
+        };
+
+        Self { accuracy, macro_f1, confusion }
+    }
+
+    fn f1_for(confusion: &HashMap<(String, String), usize>, label: &str) -> f64 {
+        let tp: usize = confusion
+            .iter()
+            .filter(|((g, p), _)| g == label && p == label)
+            .map(|(_, &c)| c)
+            .sum();
+        let fp: usize = confusion
+            .iter()
+            .filter(|((g, p), _)| p == label && g != label)
+            .map(|(_, &c)| c)
+            .sum();
+        let fn_: usize = confusion
+            .iter()
+            .filter(|((g, p), _)| g == label && p != label)
+            .map(|(_, &c)| c)
+            .sum();
+
+        let precision = if tp + fp == 0 { 0.0 } else { tp as f64 / (tp + fp) as f64 };
+        let recall = if tp + fn_ == 0 { 0.0 } else { tp as f64 / (tp + fn_) as f64 };
+        if precision + recall == 0.0 {
+            0.0
+        } else {
+            2.0 * precision * recall / (precision + recall)
+        }
+    }
+}
+
+/// Per-target metrics from k-fold cross-validation, plus the aggregate
+/// across all five `TargetField`s.
+pub struct EvalReport {
+    pub per_target: Vec<(&'static str, TargetMetrics)>,
+    pub aggregate_accuracy: f64,
+    pub aggregate_macro_f1: f64,
+}
+
+const TARGET_NAMES: [&str; 5] = ["Clinician", "GPT4.0", "LLAMA", "GEMINI", "DDX SNOMED"];
+
+/// Run k-fold cross-validation over `records`: each fold trains a
+/// `MultiTargetPredictor` on the remaining k-1 folds and predicts the held-out
+/// fold, accumulating label-vs-prediction counts (rows with no gold target
+/// are skipped, matching `get_target`'s filtering).
+pub fn cross_validate(records: &[DataRecord], params: TreeParams, k: usize) -> EvalReport {
+    let folds = make_folds(records, k);
+    let mut confusions: HashMap<&'static str, HashMap<(String, String), usize>> =
+        TARGET_NAMES.iter().map(|&name| (name, HashMap::new())).collect();
+
+    for held_out in 0..folds.len() {
+        let (train, test) = split_fold(&folds, held_out);
+        let predictor = MultiTargetPredictor::build_with_params(&train, params);
+        for rec in &test {
+            let pred = predictor.predict(rec);
+            accumulate(&mut confusions, rec, &pred);
+        }
+    }
+
+    let per_target: Vec<(&'static str, TargetMetrics)> = TARGET_NAMES
+        .iter()
+        .map(|&name| (name, TargetMetrics::from_confusion(confusions.remove(name).unwrap())))
+        .collect();
+
+    let n = per_target.len() as f64;
+    let aggregate_accuracy = per_target.iter().map(|(_, m)| m.accuracy).sum::<f64>() / n;
+    let aggregate_macro_f1 = per_target.iter().map(|(_, m)| m.macro_f1).sum::<f64>() / n;
+
+    EvalReport { per_target, aggregate_accuracy, aggregate_macro_f1 }
+}
+
+fn accumulate(
+    confusions: &mut HashMap<&'static str, HashMap<(String, String), usize>>,
+    rec: &DataRecord,
+    pred: &Prediction,
+) {
+    let pairs: [(&str, Option<&String>, Option<&str>); 5] = [
+        ("Clinician", rec.clinician.as_ref(), pred.clinician.best()),
+        ("GPT4.0", rec.gpt4_0.as_ref(), pred.gpt4_0.best()),
+        ("LLAMA", rec.llama.as_ref(), pred.llama.best()),
+        ("GEMINI", rec.gemini.as_ref(), pred.gemini.best()),
+        ("DDX SNOMED", rec.ddx_snomed.as_ref(), pred.ddx_snomed.best()),
+    ];
+    for (name, gold, predicted) in pairs {
+        if let (Some(gold), Some(predicted)) = (gold, predicted) {
+            *confusions
+                .get_mut(name)
+                .unwrap()
+                .entry((gold.clone(), predicted.to_string()))
+                .or_insert(0) += 1;
+        }
+    }
+}
+
+fn make_folds(records: &[DataRecord], k: usize) -> Vec<Vec<DataRecord>> {
+    let n = k.max(1);
+    let mut folds: Vec<Vec<DataRecord>> = vec![Vec::new(); n];
+    for (i, rec) in records.iter().enumerate() {
+        folds[i % n].push(rec.clone());
+    }
+    folds
+}
+
+fn split_fold(folds: &[Vec<DataRecord>], held_out: usize) -> (Vec<DataRecord>, Vec<DataRecord>) {
+    let train = folds
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| *i != held_out)
+        .flat_map(|(_, fold)| fold.iter().cloned())
+        .collect();
+    (train, folds[held_out].clone())
+}
+
+/// Print a per-target accuracy/macro-F1 table, plus the aggregate row.
+pub fn print_report(report: &EvalReport) {
+    println!("\n{:<12} {:>10} {:>10}", "Target", "Accuracy", "Macro-F1");
+    for (name, metrics) in &report.per_target {
+        println!("{:<12} {:>10.3} {:>10.3}", name, metrics.accuracy, metrics.macro_f1);
+    }
+    println!("{:<12} {:>10.3} {:>10.3}", "Aggregate", report.aggregate_accuracy, report.aggregate_macro_f1);
+
+    for (name, metrics) in &report.per_target {
+        print_confusion(name, &metrics.confusion);
+    }
+}
+
+/// Print the gold-vs-predicted confusion matrix for a single target.
+fn print_confusion(name: &str, confusion: &HashMap<(String, String), usize>) {
+    let labels: BTreeSet<&String> = confusion.keys().flat_map(|(g, p)| [g, p]).collect();
+    if labels.is_empty() {
+        return;
+    }
+
+    println!("\nConfusion matrix for {name} (rows = gold, cols = predicted):");
+    print!("{:<20}", "");
+    for label in &labels {
+        print!("{:>20}", label);
+    }
+    println!();
+    for gold in &labels {
+        print!("{:<20}", gold);
+        for predicted in &labels {
+            let count = confusion.get(&((*gold).clone(), (*predicted).clone())).copied().unwrap_or(0);
+            print!("{:>20}", count);
+        }
+        println!();
+    }
+}
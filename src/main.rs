@@ -1,8 +1,9 @@
 mod reader;
 mod decision_tree;
+mod eval;
 
 use crate::reader::{read_records, DataRecord};
-use decision_tree::{MultiTargetPredictor, TreeParams};
+use decision_tree::{ForestParams, MultiTargetPredictor, RandomForestPredictor, SplitQuality, TreeParams};
 use serde::Serialize;
 use std::{error::Error, fs::File, path::Path};
 
@@ -23,6 +24,14 @@ struct PredictionRecord {
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
+    let args: Vec<String> = std::env::args().collect();
+    if args.get(1).map(String::as_str) == Some("eval") {
+        return run_eval(&args[2..]);
+    }
+    if args.get(1).map(String::as_str) == Some("forest") {
+        return run_forest(&args[2..]);
+    }
+
     // 1. Load & dedupe training data
     let train_files = ["data/train.csv", "data/train_raw.csv"];
     let mut records = load_and_dedup(&train_files)?;
@@ -37,14 +46,24 @@ fn main() -> Result<(), Box<dyn Error>> {
         max_depth: 8,
         min_samples_leaf: 30,
         min_gain_ratio: 0.01,
+        split_quality: SplitQuality::GainRatio,
     };
 
-    // 4. Train with pruning
-    let predictor = MultiTargetPredictor::build_with_params(&records, params);
-    println!("Trained pruned multi-target predictor");
+    // 4. Train with pruning, or reload a previously-saved model
+    let model_path = Path::new("model.json");
+    let predictor = if model_path.exists() {
+        println!("Loading existing model from {}", model_path.display());
+        MultiTargetPredictor::load(model_path)?
+    } else {
+        let predictor = MultiTargetPredictor::build_with_params(&records, params);
+        predictor.save(model_path)?;
+        println!("Trained pruned multi-target predictor and saved it to {}", model_path.display());
+        predictor
+    };
 
     // 5. Demo
     demo(&predictor);
+    print_feature_importances(&predictor);
 
     // 6. Predict on test set
     write_predictions(&predictor, Path::new("data/test.csv"), Path::new("predictions.csv"))?;
@@ -53,6 +72,83 @@ fn main() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+/// `cargo run -- eval [k] [max_depth] [min_samples_leaf] [min_gain_ratio] [split_quality]`
+/// runs k-fold cross-validation instead of the normal train/predict pipeline,
+/// so pruning parameters and split criterion can be tuned against real
+/// accuracy/F1 numbers. `split_quality` is one of "gain_ratio" (default),
+/// "information_gain", or "gini".
+fn run_eval(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let train_files = ["data/train.csv", "data/train_raw.csv"];
+    let mut records = load_and_dedup(&train_files)?;
+    preprocess(&mut records);
+
+    let k: usize = args.first().and_then(|s| s.parse().ok()).unwrap_or(5);
+    let max_depth: usize = args.get(1).and_then(|s| s.parse().ok()).unwrap_or(8);
+    let min_samples_leaf: usize = args.get(2).and_then(|s| s.parse().ok()).unwrap_or(30);
+    let min_gain_ratio: f64 = args.get(3).and_then(|s| s.parse().ok()).unwrap_or(0.01);
+    let split_quality = match args.get(4).map(String::as_str) {
+        Some("information_gain") => SplitQuality::InformationGain,
+        Some("gini") => SplitQuality::GiniImpurity,
+        _ => SplitQuality::GainRatio,
+    };
+
+    println!(
+        "Running {}-fold cross-validation (max_depth={}, min_samples_leaf={}, min_gain_ratio={})",
+        k, max_depth, min_samples_leaf, min_gain_ratio
+    );
+    let params = TreeParams { max_depth, min_samples_leaf, min_gain_ratio, split_quality };
+    let report = eval::cross_validate(&records, params, k);
+    eval::print_report(&report);
+    Ok(())
+}
+
+/// `cargo run -- forest [n_trees] [seed]` trains a `RandomForestPredictor`
+/// instead of the single-tree `MultiTargetPredictor`, bagging `n_trees`
+/// bootstrap-sampled trees per target to trade the single tree's variance for
+/// majority-vote stability, then runs it through the same abstention-aware
+/// demo as the single-tree pipeline.
+fn run_forest(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let train_files = ["data/train.csv", "data/train_raw.csv"];
+    let mut records = load_and_dedup(&train_files)?;
+    preprocess(&mut records);
+
+    let n_trees: usize = args.first().and_then(|s| s.parse().ok()).unwrap_or(50);
+    let seed: u64 = args.get(1).and_then(|s| s.parse().ok()).unwrap_or(42);
+    let params = ForestParams {
+        tree: TreeParams {
+            max_depth: 8,
+            min_samples_leaf: 30,
+            min_gain_ratio: 0.01,
+            split_quality: SplitQuality::GainRatio,
+        },
+        n_trees,
+        feature_subset_size: None,
+        seed,
+    };
+
+    println!("Training a {}-tree random forest (seed={})", n_trees, seed);
+    let forest = RandomForestPredictor::build(&records, params);
+    demo_forest(&forest);
+    Ok(())
+}
+
+fn demo_forest(forest: &RandomForestPredictor) {
+    let example = DataRecord {
+        county: Some("Example County".into()),
+        health_level: Some("High".into()),
+        years_experience: Some("5".into()),
+        clinical_panel: Some("Panel A".into()),
+        ..Default::default()
+    };
+    let pred = forest.predict(&example);
+    println!("\nForest Example Predictions (abstaining below {:.0}% confidence):", ABSTAIN_CUTOFF * 100.0);
+    print_target("Clinician", &pred.clinician);
+    print_target("GPT4.0", &pred.gpt4_0);
+    print_target("LLAMA", &pred.llama);
+    print_target("GEMINI", &pred.gemini);
+    print_target("SNOMED", &pred.ddx_snomed);
+}
+
 fn load_and_dedup<P: AsRef<Path>>(files: &[P]) -> Result<Vec<DataRecord>, Box<dyn Error>> {
     let mut all: Vec<DataRecord> = files
         .iter()
@@ -94,6 +190,10 @@ fn preprocess(records: &mut [DataRecord]) {
     }
 }
 
+/// Minimum top-label confidence below which [`demo`] abstains rather than
+/// reporting the argmax — see `TargetPrediction::best_above`.
+const ABSTAIN_CUTOFF: f64 = 0.5;
+
 fn demo(p: &MultiTargetPredictor) {
     let example = DataRecord {
         county: Some("Example County".into()),
@@ -103,12 +203,34 @@ fn demo(p: &MultiTargetPredictor) {
         ..Default::default()
     };
     let pred = p.predict(&example);
-    println!("\nExample Predictions:");
-    println!("  Clinician: {:?}", pred.clinician);
-    println!("  GPT4.0:    {:?}", pred.gpt4_0);
-    println!("  LLAMA:     {:?}", pred.llama);
-    println!("  GEMINI:    {:?}", pred.gemini);
-    println!("  SNOMED:    {:?}", pred.ddx_snomed);
+    println!("\nExample Predictions (abstaining below {:.0}% confidence):", ABSTAIN_CUTOFF * 100.0);
+    print_target("Clinician", &pred.clinician);
+    print_target("GPT4.0", &pred.gpt4_0);
+    print_target("LLAMA", &pred.llama);
+    print_target("GEMINI", &pred.gemini);
+    print_target("SNOMED", &pred.ddx_snomed);
+}
+
+fn print_target(name: &str, pred: &decision_tree::TargetPrediction) {
+    match pred.best_above(ABSTAIN_CUTOFF) {
+        Some(label) => println!("  {:<10} {} ({:.2})", name, label, pred.confidence()),
+        None => println!(
+            "  {:<10} abstained ({:.2} < {:.0}%), runner-ups: {:?}",
+            name,
+            pred.confidence(),
+            ABSTAIN_CUTOFF * 100.0,
+            pred.runner_ups()
+        ),
+    }
+}
+
+fn print_feature_importances(p: &MultiTargetPredictor) {
+    println!("\nFeature importances:");
+    for (target, importances) in p.feature_importances() {
+        let mut ranked: Vec<(&String, &f64)> = importances.iter().collect();
+        ranked.sort_by(|a, b| b.1.partial_cmp(a.1).unwrap());
+        println!("  {:?}: {:?}", target, ranked);
+    }
 }
 
 fn write_predictions<P: AsRef<Path>>(
@@ -128,11 +250,11 @@ fn write_predictions<P: AsRef<Path>>(
         let idx = rec.master_index.unwrap_or_else(|| "N/A".into());
         let row = PredictionRecord {
             master_index: idx,
-            clinician: pred.clinician,
-            gpt4_0: pred.gpt4_0,
-            llama: pred.llama,
-            gemini: pred.gemini,
-            ddx_snomed: pred.ddx_snomed,
+            clinician: pred.clinician.best().map(String::from),
+            gpt4_0: pred.gpt4_0.best().map(String::from),
+            llama: pred.llama.best().map(String::from),
+            gemini: pred.gemini.best().map(String::from),
+            ddx_snomed: pred.ddx_snomed.best().map(String::from),
         };
         wtr.serialize(&row)?;
     }